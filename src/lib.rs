@@ -3,6 +3,12 @@ extern crate libc;
 #[macro_use]
 extern crate failure;
 
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde_derive;
+
 use std::ffi::CString;
 use std::fmt;
 use std::str;
@@ -24,14 +30,56 @@ extern "C" {
     fn h3IsValid(h: c_ulonglong) -> c_int;
     fn h3IsResClassIII(h: c_ulonglong) -> c_int;
     fn h3IsPentagon(h: c_ulonglong) -> c_int;
+    fn h3IndexesAreNeighbors(origin: c_ulonglong, destination: c_ulonglong) -> c_int;
+    fn maxFaceCount(h: c_ulonglong) -> c_int;
+    fn h3GetFaces(h: c_ulonglong, out: *mut c_int);
 
     // Traversal.
     fn h3Distance(origin: c_ulonglong, h3: c_ulonglong) -> c_int;
+    fn kRing(origin: c_ulonglong, k: c_int, out: *mut c_ulonglong);
+    fn maxKringSize(k: c_int) -> c_int;
+    fn hexRing(origin: c_ulonglong, k: c_int, out: *mut c_ulonglong) -> c_int;
 
     // Hierarchy.
     fn h3ToParent(h: c_ulonglong, parentRes: c_int) -> c_ulonglong;
+    fn h3ToChildren(h: c_ulonglong, childRes: c_int, children: *mut c_ulonglong);
+    fn h3ToCenterChild(h: c_ulonglong, childRes: c_int) -> c_ulonglong;
+
+    // Set operations.
+    #[link_name = "compact"]
+    fn compactCells(h3Set: *const c_ulonglong, compactedSet: *mut c_ulonglong, numHexes: c_int) -> c_int;
+    #[link_name = "uncompact"]
+    fn uncompactCells(
+        h3Set: *const c_ulonglong,
+        numHexes: c_int,
+        h3SetOut: *mut c_ulonglong,
+        maxH3SetOutSize: c_int,
+        res: c_int,
+    ) -> c_int;
+    fn maxUncompactSize(h3Set: *const c_ulonglong, numHexes: c_int, res: c_int) -> i64;
+
+    // Unidirectional edges.
+    fn getH3UnidirectionalEdge(origin: c_ulonglong, destination: c_ulonglong) -> c_ulonglong;
+    fn h3UnidirectionalEdgeIsValid(edge: c_ulonglong) -> c_int;
+    fn getOriginH3IndexFromUnidirectionalEdge(edge: c_ulonglong) -> c_ulonglong;
+    fn getDestinationH3IndexFromUnidirectionalEdge(edge: c_ulonglong) -> c_ulonglong;
+    fn getH3UnidirectionalEdgesFromHexagon(origin: c_ulonglong, edges: *mut c_ulonglong);
+    fn getH3UnidirectionalEdgeBoundary(edge: c_ulonglong, gp: *mut GeoBoundaryInternal);
+
+    // Local IJ coordinates (experimental).
+    fn experimentalH3ToLocalIj(origin: c_ulonglong, h3: c_ulonglong, ij: *mut CoordIJ) -> c_int;
+    fn experimentalLocalIjToH3(origin: c_ulonglong, ij: *const CoordIJ, h3: *mut c_ulonglong) -> c_int;
+
+    // Region.
+    #[link_name = "polyfill"]
+    fn polyfillCells(polygon: *const GeoPolygon, res: c_int, out: *mut c_ulonglong);
+    fn maxPolyfillSize(polygon: *const GeoPolygon, res: c_int) -> c_int;
 }
 
+/// Maximum number of unidirectional edges around a cell. The worst case is a hexagon: 6
+/// neighbors (pentagons only have 5).
+const MAX_EDGE_COUNT: usize = 6;
+
 const DEG_TO_RAD: f64 = std::f64::consts::PI / 180.0;
 const RAD_TO_DEG: f64 = 180.0 / std::f64::consts::PI;
 
@@ -43,6 +91,33 @@ const MAX_CELL_BNDRY_VERTS: usize = 10;
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
 pub struct H3Index(u64);
 
+/// Serializes an `H3Index` as its canonical lowercase hex string, e.g. for interop with the
+/// JS/Python H3 ecosystems.
+#[cfg(feature = "serde")]
+impl serde::Serialize for H3Index {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Deserializes an `H3Index` from its canonical hex string, rejecting invalid indexes at
+/// parse time via `H3Index::from_str` and `H3Index::new`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for H3Index {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        H3Index::from_str(&s)
+            .and_then(|h| H3Index::new(h.0))
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl H3Index {
     /// Creates a new `H3Index` from the given point. If the point is not a valid index in
     /// H3 then `None` is returned.
@@ -126,7 +201,12 @@ impl H3Index {
     /// # Example
     ///
     /// ```
-    ///  // TODO
+    /// extern crate h3_rs as h3;
+    /// use h3::H3Index;
+    ///
+    /// let h = H3Index::new(0x850dab63fffffff).unwrap();
+    /// let geojson = h.to_geo_boundary().to_geojson();
+    /// assert!(geojson.starts_with(r#"{"type":"Polygon""#));
     /// ```
     pub fn to_geo_boundary(self) -> GeoBoundary {
         let mut gb = GeoBoundaryInternal::new();
@@ -196,6 +276,52 @@ impl H3Index {
         unsafe { h3IsPentagon(self.0) != 0 }
     }
 
+    /// Returns a `bool` indicating whether this index and `other` are neighbors. Returns an
+    /// error if the two indexes are not of the same resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn is_neighbor(self, other: Self) -> Result<bool, Error> {
+        if self.resolution() != other.resolution() {
+            return Err(Error::IncompatibleIndexes {
+                left: self,
+                right: other,
+            });
+        }
+
+        unsafe { Ok(h3IndexesAreNeighbors(self.0, other.0) != 0) }
+    }
+
+    /// Returns the maximum number of icosahedron faces this index could possibly intersect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn max_face_count(self) -> i32 {
+        unsafe { maxFaceCount(self.0) }
+    }
+
+    /// Returns the icosahedron faces this index intersects.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn faces(self) -> Vec<i32> {
+        let max_count = self.max_face_count();
+        let mut out = vec![-1i32; max_count as usize];
+        unsafe {
+            h3GetFaces(self.0, out.as_mut_ptr());
+        }
+        out.into_iter().filter(|&f| f != -1).collect()
+    }
+
     /// Returns the distance in grid cells between two indexes or an error if finding the
     /// distance fails. Finding the distance can fail because the two indexes are not comparable
     /// (different resolutions), too far apart, or are separated by pentagonal distortion.
@@ -220,6 +346,41 @@ impl H3Index {
         Ok(d)
     }
 
+    /// Returns all indexes within grid distance `k` of this index, including the index
+    /// itself. This is also known as a "k-ring" or "grid disk".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn k_ring(self, k: i32) -> Vec<H3Index> {
+        let max_size = unsafe { maxKringSize(k) } as usize;
+        let mut out = vec![0u64; max_size];
+        unsafe {
+            kRing(self.0, k, out.as_mut_ptr());
+        }
+        out.into_iter().filter(|&h| h != 0).map(H3Index).collect()
+    }
+
+    /// Returns the hollow ring of indexes at exactly grid distance `k` from this index.
+    /// Unlike `k_ring`, this can fail when the ring is distorted by pentagonal cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn hex_ring(self, k: i32) -> Result<Vec<H3Index>, Error> {
+        let max_size = unsafe { maxKringSize(k) } as usize;
+        let mut out = vec![0u64; max_size];
+        let status = unsafe { hexRing(self.0, k, out.as_mut_ptr()) };
+        if status != 0 {
+            return Err(Error::FailedConversion);
+        }
+        Ok(out.into_iter().filter(|&h| h != 0).map(H3Index).collect())
+    }
+
     /// Returns the parent (coarser) index containing h.
     ///
     /// # Example
@@ -238,6 +399,127 @@ impl H3Index {
         }
         Ok(Self(h))
     }
+
+    /// Returns the children of this index at `child_res`. `child_res` must be between this
+    /// index's resolution and 15, otherwise `Error::FailedConversion` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn children(self, child_res: i32) -> Result<Vec<H3Index>, Error> {
+        let res = self.resolution();
+        if child_res < res || child_res > 15 {
+            return Err(Error::FailedConversion);
+        }
+
+        let n = 7i64.pow((child_res - res) as u32);
+        let count = if self.is_pentagon() {
+            1 + 5 * (n - 1) / 6
+        } else {
+            n
+        };
+
+        let mut out = vec![0u64; count as usize];
+        unsafe {
+            h3ToChildren(self.0, child_res, out.as_mut_ptr());
+        }
+        Ok(out.into_iter().filter(|&h| h != 0).map(H3Index).collect())
+    }
+
+    /// Returns the center child of this index at `child_res`. `child_res` must be between
+    /// this index's resolution and 15, otherwise `Error::FailedConversion` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn center_child(self, child_res: i32) -> Result<Self, Error> {
+        let res = self.resolution();
+        if child_res < res || child_res > 15 {
+            return Err(Error::FailedConversion);
+        }
+
+        let h;
+        unsafe {
+            h = h3ToCenterChild(self.0, child_res);
+        }
+
+        if h == 0 {
+            return Err(Error::FailedConversion);
+        }
+        Ok(Self(h))
+    }
+
+    /// Returns the unidirectional edges from this index to each of its neighbors (up to 6,
+    /// or 5 for a pentagon).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn edges(self) -> Vec<H3Edge> {
+        let mut out = [0u64; MAX_EDGE_COUNT];
+        unsafe {
+            getH3UnidirectionalEdgesFromHexagon(self.0, out.as_mut_ptr());
+        }
+        out.iter()
+            .cloned()
+            .filter(|&h| h != 0)
+            .map(H3Edge)
+            .collect()
+    }
+
+    /// Returns the unidirectional edge from this index to `dest`, or an error if the two
+    /// indexes are not neighbors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn edge_to(self, dest: Self) -> Result<H3Edge, Error> {
+        let edge;
+        unsafe {
+            edge = getH3UnidirectionalEdge(self.0, dest.0);
+        }
+
+        if edge == 0 {
+            return Err(Error::IncompatibleIndexes {
+                left: self,
+                right: dest,
+            });
+        }
+        Ok(H3Edge(edge))
+    }
+
+    /// Produces local IJ coordinates for this index anchored by `anchor`. This is
+    /// experimental: the transform is only defined for indexes reasonably close to `anchor`
+    /// and can fail across pentagon distortion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn to_local_ij(self, anchor: Self) -> Result<CoordIJ, Error> {
+        let mut ij = CoordIJ::new(0, 0);
+        let status;
+        unsafe {
+            status = experimentalH3ToLocalIj(anchor.0, self.0, &mut ij);
+        }
+
+        if status != 0 {
+            return Err(Error::IncompatibleIndexes {
+                left: anchor,
+                right: self,
+            });
+        }
+        Ok(ij)
+    }
 }
 
 impl fmt::Display for H3Index {
@@ -255,6 +537,94 @@ impl fmt::Display for H3Index {
     }
 }
 
+/// H3Edge is a directed edge from one H3Index to a neighboring one, used to represent the
+/// boundary shared between two adjacent cells.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub struct H3Edge(u64);
+
+impl H3Edge {
+    /// Creates a new `H3Edge` from the given value. If the value is not a valid edge then
+    /// an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn new(e: u64) -> Result<Self, Error> {
+        let valid;
+        unsafe {
+            valid = h3UnidirectionalEdgeIsValid(e);
+        }
+        if valid == 0 {
+            return Err(Error::InvalidIndex { value: e });
+        }
+        Ok(Self(e))
+    }
+
+    /// Returns the origin index of this edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn origin(self) -> H3Index {
+        unsafe { H3Index(getOriginH3IndexFromUnidirectionalEdge(self.0)) }
+    }
+
+    /// Returns the destination index of this edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn destination(self) -> H3Index {
+        unsafe { H3Index(getDestinationH3IndexFromUnidirectionalEdge(self.0)) }
+    }
+
+    /// Finds the boundary of this edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn to_geo_boundary(self) -> GeoBoundary {
+        let mut gb = GeoBoundaryInternal::new();
+        unsafe {
+            getH3UnidirectionalEdgeBoundary(self.0, &mut gb);
+        }
+        gb.convert()
+    }
+}
+
+/// CoordIJ is a coordinate on a local planar grid anchored to a particular H3 index,
+/// mirroring the layout of the C `CoordIJ` struct.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CoordIJ {
+    pub i: i32,
+    pub j: i32,
+}
+
+impl CoordIJ {
+    /// Creates a new `CoordIJ` from the given coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate h3_rs as h3;
+    /// use h3::CoordIJ;
+    ///
+    /// let ij = CoordIJ::new(1, -1);
+    /// ```
+    pub fn new(i: i32, j: i32) -> Self {
+        Self { i, j }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GeoCoordInternal {
@@ -280,6 +650,7 @@ impl GeoCoordInternal {
 /// degrees. The C API for H3 expects the latitude and longitude to be expressed in radians so
 /// the coordinates are transparently converted to radians before being passed to the C library.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GeoCoord {
     pub lat: f64,
     pub lon: f64,
@@ -351,10 +722,204 @@ impl GeoBoundaryInternal {
 
 /// GeoBoundary is a collection of points which defines the boundary of a cell.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GeoBoundary {
     pub verts: Vec<GeoCoord>,
 }
 
+impl GeoBoundary {
+    /// Serializes this boundary to a GeoJSON `Polygon` geometry, repeating the first vertex
+    /// at the end of the ring as required by the GeoJSON spec.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // TODO
+    /// ```
+    pub fn to_geojson(&self) -> String {
+        format!(
+            r#"{{"type":"Polygon","coordinates":[{}]}}"#,
+            geojson_ring(&self.verts)
+        )
+    }
+}
+
+#[repr(C)]
+struct Geofence {
+    num_verts: c_int,
+    verts: *mut GeoCoordInternal,
+}
+
+#[repr(C)]
+struct GeoPolygon {
+    geofence: Geofence,
+    num_holes: c_int,
+    holes: *mut Geofence,
+}
+
+fn geojson_ring(verts: &[GeoCoord]) -> String {
+    let mut coords: Vec<String> = verts
+        .iter()
+        .map(|v| format!("[{},{}]", v.lon, v.lat))
+        .collect();
+    if let Some(first) = coords.first().cloned() {
+        coords.push(first);
+    }
+    format!("[{}]", coords.join(","))
+}
+
+/// Compacts a set of H3 indexes as best as possible. Any set of all seven children of a
+/// common parent is replaced by that parent, recursively up the resolution hierarchy, so the
+/// result may span multiple resolutions. Fails if `cells` contains duplicates or indexes of
+/// differing resolutions.
+///
+/// # Example
+///
+/// ```
+/// // TODO
+/// ```
+pub fn compact(cells: &[H3Index]) -> Result<Vec<H3Index>, Error> {
+    let h3_set: Vec<u64> = cells.iter().map(|h| h.0).collect();
+    let mut out = vec![0u64; h3_set.len()];
+
+    let status;
+    unsafe {
+        status = compactCells(h3_set.as_ptr(), out.as_mut_ptr(), h3_set.len() as c_int);
+    }
+
+    if status != 0 {
+        return Err(Error::FailedConversion);
+    }
+    Ok(out.into_iter().filter(|&h| h != 0).map(H3Index).collect())
+}
+
+/// Expands a (possibly mixed-resolution) set of H3 indexes to a uniform set of indexes at
+/// `res`. Every cell in `cells` must be at a resolution no finer than `res`.
+///
+/// # Example
+///
+/// ```
+/// // TODO
+/// ```
+pub fn uncompact(cells: &[H3Index], res: i32) -> Result<Vec<H3Index>, Error> {
+    let h3_set: Vec<u64> = cells.iter().map(|h| h.0).collect();
+
+    let max_size;
+    unsafe {
+        max_size = maxUncompactSize(h3_set.as_ptr(), h3_set.len() as c_int, res);
+    }
+
+    if max_size < 0 {
+        return Err(Error::FailedConversion);
+    }
+
+    let mut out = vec![0u64; max_size as usize];
+    let status;
+    unsafe {
+        status = uncompactCells(
+            h3_set.as_ptr(),
+            h3_set.len() as c_int,
+            out.as_mut_ptr(),
+            max_size as c_int,
+            res,
+        );
+    }
+
+    if status != 0 {
+        return Err(Error::FailedConversion);
+    }
+    Ok(out.into_iter().filter(|&h| h != 0).map(H3Index).collect())
+}
+
+/// Produces the H3 index at `ij` in the local planar grid anchored by `anchor`. This is
+/// experimental: the transform is only defined for coordinates reasonably close to `anchor`
+/// and can fail across pentagon distortion.
+///
+/// # Example
+///
+/// ```
+/// // TODO
+/// ```
+pub fn local_ij_to_h3(anchor: H3Index, ij: CoordIJ) -> Result<H3Index, Error> {
+    let h;
+    unsafe {
+        let mut out = 0u64;
+        let status = experimentalLocalIjToH3(anchor.0, &ij, &mut out);
+        if status != 0 {
+            return Err(Error::IncompatibleIndexes {
+                left: anchor,
+                right: anchor,
+            });
+        }
+        h = out;
+    }
+    Ok(H3Index(h))
+}
+
+/// Fills a polygon (with optional holes) with all H3 indexes of resolution `res` whose
+/// centroids fall inside it. `outline` and each hole in `holes` are expressed in degrees and
+/// need not be explicitly closed.
+///
+/// # Example
+///
+/// ```
+/// // TODO
+/// ```
+pub fn polyfill(outline: &[GeoCoord], holes: &[Vec<GeoCoord>], res: i32) -> Vec<H3Index> {
+    let mut outline_verts: Vec<GeoCoordInternal> =
+        outline.iter().map(|g| g.to_radians()).collect();
+    let mut hole_verts: Vec<Vec<GeoCoordInternal>> = holes
+        .iter()
+        .map(|h| h.iter().map(|g| g.to_radians()).collect())
+        .collect();
+    let mut hole_fences: Vec<Geofence> = hole_verts
+        .iter_mut()
+        .map(|h| Geofence {
+            num_verts: h.len() as c_int,
+            verts: h.as_mut_ptr(),
+        })
+        .collect();
+
+    let polygon = GeoPolygon {
+        geofence: Geofence {
+            num_verts: outline_verts.len() as c_int,
+            verts: outline_verts.as_mut_ptr(),
+        },
+        num_holes: hole_fences.len() as c_int,
+        holes: hole_fences.as_mut_ptr(),
+    };
+
+    let max_size;
+    unsafe {
+        max_size = maxPolyfillSize(&polygon, res);
+    }
+
+    let mut out = vec![0u64; max_size as usize];
+    unsafe {
+        polyfillCells(&polygon, res, out.as_mut_ptr());
+    }
+    out.into_iter().filter(|&h| h != 0).map(H3Index).collect()
+}
+
+/// Serializes a coverage (a set of cells, e.g. the output of `polyfill`) to a GeoJSON
+/// `MultiPolygon` geometry, one polygon per cell boundary.
+///
+/// # Example
+///
+/// ```
+/// // TODO
+/// ```
+pub fn coverage_to_geojson(cells: &[H3Index]) -> String {
+    let polygons: Vec<String> = cells
+        .iter()
+        .map(|h| format!("[{}]", geojson_ring(&h.to_geo_boundary().verts)))
+        .collect();
+    format!(
+        r#"{{"type":"MultiPolygon","coordinates":[{}]}}"#,
+        polygons.join(",")
+    )
+}
+
 #[derive(Debug, Fail)]
 pub enum Error {
     #[fail(display = "invalid value for H3 index: {}", value)]
@@ -405,7 +970,10 @@ mod tests {
 
     #[test]
     fn test_h3_to_geo_boundary() {
-        // TODO
+        let setup = Setup::new();
+
+        let geojson = setup.valid_index.to_geo_boundary().to_geojson();
+        assert!(geojson.starts_with(r#"{"type":"Polygon""#));
     }
 
     #[test]
@@ -445,6 +1013,26 @@ mod tests {
         assert!(setup.pentagon_index.is_pentagon());
     }
 
+    #[test]
+    fn test_h3_is_neighbor() {
+        let setup = Setup::new();
+
+        let neighbor = setup.valid_index.k_ring(1)[1];
+        assert!(setup.valid_index.is_neighbor(neighbor).unwrap());
+
+        let parent = setup.valid_index.parent(setup.valid_index.resolution() - 1).unwrap();
+        assert!(setup.valid_index.is_neighbor(parent).is_err());
+    }
+
+    #[test]
+    fn test_h3_faces() {
+        let setup = Setup::new();
+
+        let faces = setup.valid_index.faces();
+        assert!(!faces.is_empty());
+        assert!(faces.len() <= setup.valid_index.max_face_count() as usize);
+    }
+
     #[test]
     fn test_h3_distance() {
         // let setup = Setup::new();
@@ -459,6 +1047,101 @@ mod tests {
         // TODO
     }
 
+    #[test]
+    fn test_h3_k_ring() {
+        let setup = Setup::new();
+
+        let ring = setup.valid_index.k_ring(1);
+        assert_eq!(ring.len(), 7);
+        assert!(ring.contains(&setup.valid_index));
+    }
+
+    #[test]
+    fn test_h3_hex_ring() {
+        // let setup = Setup::new();
+
+        // TODO
+    }
+
+    #[test]
+    fn test_h3_children() {
+        let setup = Setup::new();
+
+        let children = setup.valid_index.children(setup.valid_index.resolution() + 1).unwrap();
+        assert_eq!(children.len(), 7);
+
+        assert!(setup.valid_index.children(setup.valid_index.resolution() - 1).is_err());
+        assert!(setup.valid_index.children(16).is_err());
+    }
+
+    #[test]
+    fn test_h3_center_child() {
+        // let setup = Setup::new();
+
+        // TODO
+    }
+
+    #[test]
+    fn test_h3_edges() {
+        let setup = Setup::new();
+
+        let edges = setup.valid_index.edges();
+        assert_eq!(edges.len(), 6);
+        for edge in edges {
+            assert_eq!(edge.origin(), setup.valid_index);
+        }
+    }
+
+    #[test]
+    fn test_h3_edge_to() {
+        let setup = Setup::new();
+
+        let neighbor = setup.valid_index.k_ring(1)[1];
+        let edge = setup.valid_index.edge_to(neighbor).unwrap();
+        assert_eq!(edge.origin(), setup.valid_index);
+        assert_eq!(edge.destination(), neighbor);
+    }
+
+    #[test]
+    fn test_polyfill() {
+        let setup = Setup::new();
+
+        let center = setup.valid_geo_coord;
+        let outline = vec![
+            GeoCoord::new(center.lat - 1.0, center.lon - 1.0),
+            GeoCoord::new(center.lat - 1.0, center.lon + 1.0),
+            GeoCoord::new(center.lat + 1.0, center.lon + 1.0),
+            GeoCoord::new(center.lat + 1.0, center.lon - 1.0),
+        ];
+
+        let cells = polyfill(&outline, &[], setup.valid_index.resolution());
+        assert!(!cells.is_empty());
+        assert!(cells.contains(&setup.valid_index));
+
+        let geojson = coverage_to_geojson(&cells);
+        assert!(geojson.starts_with(r#"{"type":"MultiPolygon""#));
+    }
+
+    #[test]
+    fn test_h3_to_local_ij() {
+        let setup = Setup::new();
+
+        let ij = setup.valid_index.to_local_ij(setup.valid_index).unwrap();
+        assert_eq!(local_ij_to_h3(setup.valid_index, ij).unwrap(), setup.valid_index);
+    }
+
+    #[test]
+    fn test_compact_uncompact() {
+        let setup = Setup::new();
+
+        let children = setup.valid_index.children(setup.valid_index.resolution() + 1).unwrap();
+        let compacted = compact(&children).unwrap();
+        assert_eq!(compacted, vec![setup.valid_index]);
+
+        let uncompacted = uncompact(&compacted, setup.valid_index.resolution() + 1).unwrap();
+        assert_eq!(uncompacted.len(), children.len());
+    }
+
     #[test]
     fn test_h3_display() {
         let setup = Setup::new();
@@ -466,6 +1149,20 @@ mod tests {
         assert_eq!(format!("{}", setup.valid_index), "850dab63fffffff");
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_h3_serde_roundtrip() {
+        let setup = Setup::new();
+
+        let json = serde_json::to_string(&setup.valid_index).unwrap();
+        assert_eq!(json, r#""850dab63fffffff""#);
+        assert_eq!(
+            serde_json::from_str::<H3Index>(&json).unwrap(),
+            setup.valid_index
+        );
+        assert!(serde_json::from_str::<H3Index>(r#""not an index""#).is_err());
+    }
+
     #[test]
     fn test_geo_to_h3() {
         let setup = Setup::new();